@@ -1,3 +1,4 @@
+use chrono::{DateTime, Local, LocalResult, NaiveDateTime, TimeZone};
 use clap::Parser;
 use itertools::Itertools;
 use rustysynth::MidiFile;
@@ -7,7 +8,10 @@ use rustysynth::Synthesizer;
 use rustysynth::SynthesizerSettings;
 use std::collections::HashMap;
 use std::fs::File;
+use std::io::Write;
+use std::net::{SocketAddr, UdpSocket};
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use tinyaudio::prelude::*;
 
 // CC state per channel
@@ -149,8 +153,37 @@ struct Args {
     /// Per-channel parameter: CHANNEL:PARAM:VALUE (e.g., 0:volume:100, 1:pan:50)
     /// Can be specified multiple times. PARAM can be: volume, pan, reverb, chorus, modulation, expression, sustain
     /// Channel numbers are 0-15. For sustain, use 0 or 1 (off/on) instead of 0-127.
+    /// CHANNEL may also be a group name defined with --group (e.g., strings:volume:90).
     #[arg(long = "channel-param", value_name = "CHANNEL:PARAM:VALUE", num_args = 1..)]
     channel_params: Vec<String>,
+    
+    /// Channel group: NAME=CHANNELS (e.g., strings=0,1,2)
+    /// Can be specified multiple times. Channel numbers are 0-15.
+    #[arg(long = "group", value_name = "NAME=CHANNELS", num_args = 1..)]
+    groups: Vec<String>,
+    
+    /// Start playback at a wall-clock time: YYYY-MM-DDTHH:MM:SS (local time)
+    /// An explicit offset is also accepted (e.g., 2024-12-31T23:59:30Z or 2024-12-31T23:59:30+01:00).
+    #[arg(long = "start-at", value_name = "TIME", conflicts_with = "start_in")]
+    start_at: Option<String>,
+    
+    /// Start playback after a delay (e.g., 90s, 5m, 1h30m, 500ms). A bare number is seconds.
+    #[arg(long = "start-in", value_name = "DURATION")]
+    start_in: Option<String>,
+    
+    /// Act as sync leader: broadcast the start time and playback position to followers on the LAN
+    /// Followers must be loaded and listening before the start time, or they cannot join.
+    /// Use --start-at/--start-in if followers need longer to load [default start: 10s after loading]
+    #[arg(long = "sync-leader")]
+    sync_leader: bool,
+    
+    /// Act as sync follower: wait for a leader's start broadcast and track its playback position
+    #[arg(long = "sync-follower", conflicts_with_all = ["sync_leader", "start_at", "start_in"])]
+    sync_follower: bool,
+    
+    /// UDP port used for leader/follower sync
+    #[arg(long = "sync-port", value_name = "PORT", default_value_t = SYNC_DEFAULT_PORT)]
+    sync_port: u16,
 }
 
 // MIDI CC message constants
@@ -163,6 +196,97 @@ const CC_EXPRESSION: i32 = 11;
 const CC_SUSTAIN: i32 = 64;
 const MIDI_CC_COMMAND: i32 = 0xB0; // Control Change message
 
+// Scheduled start: how long before the start time it is converted to the monotonic clock
+const SCHEDULE_ARM_MS: u64 = 1000;
+
+// Network sync constants
+const SYNC_DEFAULT_PORT: u16 = 47800;
+const SYNC_MAGIC: &str = "RSPSYNC1"; // Prefix identifying our datagrams on the port
+const SYNC_DEFAULT_LEAD_MS: u64 = 10000; // Leader start delay when no start time is given
+const SYNC_FOLLOWER_ARM_MS: u64 = 1000; // Follower stops listening this long before the start to open the device
+const SYNC_START_INTERVAL_MS: u64 = 250; // How often the leader repeats the start time
+const SYNC_POSITION_INTERVAL_MS: u64 = 1000; // How often the leader sends position corrections
+const SYNC_DRIFT_TOLERANCE: f64 = 0.005; // Seconds of drift ignored by followers
+const SYNC_CORRECTION_GAIN: f64 = 0.5; // Fraction of the drift corrected per second
+const SYNC_MAX_SPEED_ADJUST: f64 = 0.02; // Max playback speed deviation used for corrections
+const SYNC_MAX_TIMESTAMP_MS: i64 = 253_402_300_799_999; // 9999-12-31T23:59:59.999Z, bounds datagram timestamps
+
+// Last rendered sequencer position and when it was rendered.
+// Used to extrapolate the playback position between audio callbacks.
+#[derive(Clone, Copy, Debug)]
+struct Playhead {
+    position: f64,
+    speed: f64,
+    rendered_at: Instant,
+}
+
+impl Playhead {
+    fn position_at(&self, now: Instant) -> f64 {
+        self.position + now.saturating_duration_since(self.rendered_at).as_secs_f64() * self.speed
+    }
+}
+
+// Datagrams exchanged between leader and followers.
+// Timestamps are milliseconds since the Unix epoch on the leader's clock.
+#[derive(Clone, Copy, Debug)]
+enum SyncMessage {
+    Start { sent_at_ms: i64, start_at_ms: i64 },
+    Position { sent_at_ms: i64, position: f64 },
+}
+
+impl SyncMessage {
+    fn encode(&self) -> String {
+        match self {
+            SyncMessage::Start { sent_at_ms, start_at_ms } => {
+                format!("{} START {} {}", SYNC_MAGIC, sent_at_ms, start_at_ms)
+            }
+            SyncMessage::Position { sent_at_ms, position } => {
+                format!("{} POS {} {}", SYNC_MAGIC, sent_at_ms, position)
+            }
+        }
+    }
+
+    fn decode(datagram: &[u8]) -> Option<SyncMessage> {
+        let text = std::str::from_utf8(datagram).ok()?;
+        let parts: Vec<&str> = text.split_whitespace().collect();
+        if parts.len() != 4 || parts[0] != SYNC_MAGIC {
+            return None;
+        }
+        // Datagrams are untrusted: keep timestamps in a range where clock arithmetic cannot overflow
+        let parse_timestamp = |value: &str| {
+            value.parse::<i64>().ok().filter(|ms| (-SYNC_MAX_TIMESTAMP_MS..=SYNC_MAX_TIMESTAMP_MS).contains(ms))
+        };
+        let sent_at_ms = parse_timestamp(parts[2])?;
+        match parts[1] {
+            "START" => Some(SyncMessage::Start {
+                sent_at_ms,
+                start_at_ms: parse_timestamp(parts[3])?,
+            }),
+            "POS" => Some(SyncMessage::Position {
+                sent_at_ms,
+                position: parts[3].parse::<f64>().ok().filter(|p| p.is_finite())?,
+            }),
+            _ => None,
+        }
+    }
+}
+
+fn unix_millis(time: SystemTime) -> i64 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(since) => since.as_millis() as i64,
+        Err(e) => -(e.duration().as_millis() as i64),
+    }
+}
+
+// None if the time can't be represented on this platform
+fn from_unix_millis(ms: i64) -> Option<SystemTime> {
+    if ms >= 0 {
+        UNIX_EPOCH.checked_add(Duration::from_millis(ms as u64))
+    } else {
+        UNIX_EPOCH.checked_sub(Duration::from_millis(ms.unsigned_abs()))
+    }
+}
+
 // Send CC messages from state manager to synthesizer
 // SAFETY: This function uses unsafe to convert &Synthesizer to &mut Synthesizer.
 // This is safe because:
@@ -225,9 +349,371 @@ unsafe fn send_cc_messages_from_state(
     }
 }
 
+// Parse a MIDI channel number (0-15)
+fn parse_channel(value: &str) -> Result<i32, String> {
+    match value.trim().parse::<i32>() {
+        Ok(ch) if (0..16).contains(&ch) => Ok(ch),
+        Ok(ch) => Err(format!("Channel number must be 0-15, got {}", ch)),
+        Err(e) => Err(format!("Invalid channel number '{}': {}", value, e)),
+    }
+}
+
+// Parse a --start-at value into an absolute point in time
+// Times without an offset are interpreted in the local time zone
+fn parse_start_at(value: &str) -> Result<SystemTime, String> {
+    if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+        return Ok(time.into());
+    }
+    
+    let naive = NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S")
+        .or_else(|_| NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S"))
+        .map_err(|e| format!("Invalid start time '{}': {}. Expected YYYY-MM-DDTHH:MM:SS", value, e))?;
+    
+    resolve_local_start(value, Local.from_local_datetime(&naive))
+}
+
+// Pick a single point in time for a local start time that may fall into a DST transition
+fn resolve_local_start<Tz: TimeZone>(value: &str, local: LocalResult<DateTime<Tz>>) -> Result<SystemTime, String> {
+    match local {
+        LocalResult::Single(time) => Ok(time.into()),
+        // Clocks went back (DST end): the first occurrence is the one that comes soonest
+        LocalResult::Ambiguous(earliest, _) => Ok(earliest.into()),
+        LocalResult::None => Err(format!("Start time '{}' does not exist in the local time zone", value)),
+    }
+}
+
+// Parse a --start-in value such as "90s", "1m30s", "500ms" or "2h" into a duration
+// A bare number is taken as seconds
+fn parse_start_in(value: &str) -> Result<Duration, String> {
+    let value = value.trim().to_lowercase();
+    if value.is_empty() {
+        return Err("Start delay must not be empty. Example: 90s, 5m, 1h30m, 500ms".to_string());
+    }
+    if let Ok(seconds) = value.parse::<f64>() {
+        if seconds.is_finite() && seconds >= 0.0 {
+            return Duration::try_from_secs_f64(seconds)
+                .map_err(|_| format!("Start delay '{}' is too large", value));
+        }
+        return Err(format!("Invalid start delay '{}': must be a non-negative number", value));
+    }
+    
+    let mut total_seconds = 0.0;
+    let mut chars = value.chars().peekable();
+    while chars.peek().is_some() {
+        let mut number = String::new();
+        while let Some(&c) = chars.peek() {
+            if c.is_ascii_digit() || c == '.' {
+                number.push(c);
+                chars.next();
+            } else {
+                break;
+            }
+        }
+        
+        let mut unit = String::new();
+        while let Some(&c) = chars.peek() {
+            if c.is_ascii_alphabetic() {
+                unit.push(c);
+                chars.next();
+            } else {
+                break;
+            }
+        }
+        
+        let amount = number.parse::<f64>()
+            .map_err(|_| format!("Invalid start delay '{}'. Example: 90s, 5m, 1h30m, 500ms", value))?;
+        let scale = match unit.as_str() {
+            "ms" => 0.001,
+            "s" => 1.0,
+            "m" => 60.0,
+            "h" => 3600.0,
+            _ => {
+                return Err(format!("Invalid unit '{}' in start delay '{}'. Use ms, s, m or h", unit, value));
+            }
+        };
+        total_seconds += amount * scale;
+    }
+    
+    Duration::try_from_secs_f64(total_seconds)
+        .map_err(|_| format!("Start delay '{}' is too large", value))
+}
+
+// Format a countdown as M:SS or H:MM:SS
+fn format_countdown(total_seconds: u64) -> String {
+    let hours = total_seconds / 3600;
+    let minutes = (total_seconds / 60) % 60;
+    let seconds = total_seconds % 60;
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{}:{:02}", minutes, seconds)
+    }
+}
+
+// Block until shortly before the given wall-clock time, keeping a countdown on a single line
+// Returns SCHEDULE_ARM_MS early so the caller can pin the exact start to the monotonic clock.
+// The wall clock is re-read at least every second, so clock adjustments during the wait are followed.
+fn wait_until_near(target: SystemTime) {
+    let arm = Duration::from_millis(SCHEDULE_ARM_MS);
+    let mut last_announced = None;
+    loop {
+        let remaining = target.duration_since(SystemTime::now()).unwrap_or_default();
+        if remaining <= arm {
+            break;
+        }
+        
+        let whole_seconds = remaining.as_secs();
+        if last_announced != Some(whole_seconds) {
+            print!("\rStarting in {}   ", format_countdown(whole_seconds));
+            let _ = std::io::stdout().flush();
+            last_announced = Some(whole_seconds);
+        }
+        let until_next_second = Duration::from_nanos(remaining.subsec_nanos() as u64);
+        std::thread::sleep(until_next_second.max(Duration::from_millis(1)).min(remaining - arm));
+    }
+    if last_announced.is_some() {
+        println!();
+    }
+}
+
+// Leader side of network sync: repeat the start time until playback begins,
+// then broadcast the current playback position at a fixed interval.
+// Runs until the process exits.
+fn run_sync_leader(port: u16, start_at: SystemTime, playhead: Arc<Mutex<Option<Playhead>>>) {
+    let socket = UdpSocket::bind(("0.0.0.0", 0)).unwrap_or_else(|e| {
+        eprintln!("Error: Cannot open sync socket: {}", e);
+        std::process::exit(1);
+    });
+    if let Err(e) = socket.set_broadcast(true) {
+        eprintln!("Error: Cannot enable broadcast on sync socket: {}", e);
+        std::process::exit(1);
+    }
+    
+    let start_at_ms = unix_millis(start_at);
+    let mut warned = false;
+    loop {
+        let now = SystemTime::now();
+        let message = if now < start_at {
+            Some(SyncMessage::Start { sent_at_ms: unix_millis(now), start_at_ms })
+        } else {
+            let current = *playhead.lock().unwrap();
+            current.map(|p| SyncMessage::Position {
+                sent_at_ms: unix_millis(SystemTime::now()),
+                position: p.position_at(Instant::now()),
+            })
+        };
+        
+        if let Some(message) = message {
+            if let Err(e) = socket.send_to(message.encode().as_bytes(), ("255.255.255.255", port)) {
+                if !warned {
+                    eprintln!("Warning: Failed to send sync broadcast: {}", e);
+                    warned = true;
+                }
+            }
+        }
+        
+        let interval = if now < start_at { SYNC_START_INTERVAL_MS } else { SYNC_POSITION_INTERVAL_MS };
+        std::thread::sleep(Duration::from_millis(interval));
+    }
+}
+
+// Follower state while waiting for the leader's start time.
+// Locks onto the first leader that announces a start and ignores datagrams from anyone else.
+#[derive(Debug, Default)]
+struct SyncStartTracker {
+    leader: Option<SocketAddr>,
+    clock_offset_ms: Option<i64>, // Local minus leader clock, smallest value seen (least network delay)
+    start_at_ms: Option<i64>,     // Start time on the leader's clock
+}
+
+impl SyncStartTracker {
+    // Handle one received datagram.
+    // `stale` marks datagrams that queued up while we were loading: they still identify the leader
+    // and reveal whether it is already playing, but their receive time can't be used for the clock offset.
+    fn receive(&mut self, datagram: &[u8], from: SocketAddr, received_at_ms: i64, stale: bool) -> Result<(), String> {
+        let message = match SyncMessage::decode(datagram) {
+            Some(message) => message,
+            None => return Ok(()),
+        };
+        if self.leader.is_some_and(|leader| leader != from) {
+            return Ok(());
+        }
+        
+        match message {
+            SyncMessage::Start { sent_at_ms, start_at_ms } => {
+                self.leader = Some(from);
+                self.start_at_ms = Some(start_at_ms);
+                if !stale {
+                    if let Some(offset) = received_at_ms.checked_sub(sent_at_ms) {
+                        self.clock_offset_ms = Some(self.clock_offset_ms.map_or(offset, |best| best.min(offset)));
+                    }
+                }
+            }
+            // The leader only reports positions once playing, so without a fresh start time we missed it
+            SyncMessage::Position { .. } if self.clock_offset_ms.is_none() => {
+                return Err("Sync leader is already playing; the follower must be started before the leader's start time".to_string());
+            }
+            SyncMessage::Position { .. } => {}
+        }
+        Ok(())
+    }
+
+    // The leader's start time in local wall-clock milliseconds, once known
+    fn local_start_ms(&self) -> Option<i64> {
+        self.start_at_ms?.checked_add(self.clock_offset_ms?)
+    }
+}
+
+// Follower side of network sync: wait for the leader's start time and translate it to the local clock.
+// Returns the local start time, the estimated clock offset (local minus leader, in ms) and the leader's address.
+fn wait_for_sync_start(socket: &UdpSocket) -> (SystemTime, i64, SocketAddr) {
+    let mut buf = [0_u8; 256];
+    let mut tracker = SyncStartTracker::default();
+    let handle = |tracker: &mut SyncStartTracker, datagram: &[u8], from: SocketAddr, stale: bool| {
+        if let Err(e) = tracker.receive(datagram, from, unix_millis(SystemTime::now()), stale) {
+            eprintln!("Error: {}", e);
+            eprintln!("Use --start-at/--start-in on the leader to give followers more time to load");
+            eprintln!("Separate groups of players on the same network need different --sync-port values");
+            std::process::exit(1);
+        }
+    };
+    let receive_error = |e: std::io::Error| -> ! {
+        eprintln!("Error: Failed to receive sync message: {}", e);
+        std::process::exit(1);
+    };
+    
+    // Read everything that arrived while we were loading before making any decision
+    if let Err(e) = socket.set_nonblocking(true) {
+        eprintln!("Error: Cannot configure sync socket: {}", e);
+        std::process::exit(1);
+    }
+    loop {
+        match socket.recv_from(&mut buf) {
+            Ok((len, from)) => handle(&mut tracker, &buf[..len], from, true),
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => break,
+            Err(e) => receive_error(e),
+        }
+    }
+    
+    if let Err(e) = socket
+        .set_nonblocking(false)
+        .and_then(|_| socket.set_read_timeout(Some(Duration::from_millis(50))))
+    {
+        eprintln!("Error: Cannot configure sync socket: {}", e);
+        std::process::exit(1);
+    }
+    loop {
+        let local_start = tracker.local_start_ms().and_then(from_unix_millis);
+        if let (Some(local_start), Some(leader)) = (local_start, tracker.leader) {
+            // Stop listening shortly before the start, leaving time to open the audio device
+            let remaining = local_start.duration_since(SystemTime::now()).unwrap_or_default();
+            if remaining < Duration::from_millis(SYNC_FOLLOWER_ARM_MS) {
+                return (local_start, tracker.clock_offset_ms.unwrap_or_default(), leader);
+            }
+        }
+        
+        match socket.recv_from(&mut buf) {
+            Ok((len, from)) => handle(&mut tracker, &buf[..len], from, false),
+            Err(e) if matches!(e.kind(), std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut) => {}
+            Err(e) => receive_error(e),
+        }
+    }
+}
+
+// Follower side of network sync after the start: compare our playback position with the
+// leader's and nudge the sequencer speed so the drift is corrected gradually.
+// Runs until the process exits.
+fn run_sync_follower(
+    socket: UdpSocket,
+    leader: SocketAddr,
+    clock_offset_ms: i64,
+    sequencer: Arc<Mutex<MidiFileSequencer>>,
+    playhead: Arc<Mutex<Option<Playhead>>>,
+) {
+    if let Err(e) = socket.set_read_timeout(None) {
+        eprintln!("Warning: Cannot configure sync socket, position corrections disabled: {}", e);
+        return;
+    }
+    
+    let mut buf = [0_u8; 256];
+    loop {
+        let len = match socket.recv_from(&mut buf) {
+            Ok((len, from)) if from == leader => len,
+            Ok(_) => continue,
+            Err(e) => {
+                eprintln!("Warning: Sync connection lost, position corrections disabled: {}", e);
+                return;
+            }
+        };
+        let (sent_at_ms, leader_position) = match SyncMessage::decode(&buf[..len]) {
+            Some(SyncMessage::Position { sent_at_ms, position }) => (sent_at_ms, position),
+            _ => continue,
+        };
+        let current = match *playhead.lock().unwrap() {
+            Some(current) => current,
+            None => continue,
+        };
+        
+        // Where the leader is now, on our clock
+        let sent_at = match sent_at_ms.checked_add(clock_offset_ms).and_then(from_unix_millis) {
+            Some(sent_at) => sent_at,
+            None => continue,
+        };
+        let in_flight = SystemTime::now().duration_since(sent_at).unwrap_or_default();
+        let expected = leader_position + in_flight.as_secs_f64();
+        
+        // Positive drift means we are behind the leader
+        let drift = expected - current.position_at(Instant::now());
+        let speed = if drift.abs() < SYNC_DRIFT_TOLERANCE {
+            1.0
+        } else {
+            1.0 + (drift * SYNC_CORRECTION_GAIN).clamp(-SYNC_MAX_SPEED_ADJUST, SYNC_MAX_SPEED_ADJUST)
+        };
+        sequencer.lock().unwrap().set_speed(speed);
+    }
+}
+
 fn main() {
     let args = Args::parse();
     
+    // Resolve the scheduled start before loading anything, so --start-in counts from invocation
+    let scheduled_start = if let Some(start_at) = &args.start_at {
+        let target = parse_start_at(start_at).unwrap_or_else(|e| {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        });
+        if target <= SystemTime::now() {
+            eprintln!("Error: Start time '{}' is in the past", start_at);
+            std::process::exit(1);
+        }
+        Some(target)
+    } else if let Some(start_in) = &args.start_in {
+        let delay = parse_start_in(start_in).unwrap_or_else(|e| {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        });
+        match SystemTime::now().checked_add(delay) {
+            Some(target) => Some(target),
+            None => {
+                eprintln!("Error: Start delay '{}' is too large", start_in);
+                std::process::exit(1);
+            }
+        }
+    } else {
+        None
+    };
+    
+    // Bind the follower socket up front so a busy port is reported before loading
+    let sync_socket = if args.sync_follower {
+        let socket = UdpSocket::bind(("0.0.0.0", args.sync_port)).unwrap_or_else(|e| {
+            eprintln!("Error: Cannot listen for sync leader on port {}: {}", args.sync_port, e);
+            std::process::exit(1);
+        });
+        Some(socket)
+    } else {
+        None
+    };
+    
     let soundfont_path = &args.soundfont;
     let midi_path = &args.midi_file;
 
@@ -319,6 +805,42 @@ fn main() {
     };
     cc_state_manager.set_global_cc("sustain", sustain_value);
     
+    // Parse channel groups
+    let mut channel_groups: HashMap<String, Vec<i32>> = HashMap::new();
+    for group_str in &args.groups {
+        // Parse format: NAME=CHANNEL,CHANNEL,...
+        let (name, channel_list) = match group_str.split_once('=') {
+            Some((name, channel_list)) => (name.trim().to_lowercase(), channel_list),
+            None => {
+                eprintln!("Error: Invalid group format '{}'. Expected NAME=CHANNELS", group_str);
+                eprintln!("Example: --group strings=0,1,2");
+                std::process::exit(1);
+            }
+        };
+        
+        // Names must not be mistaken for channel numbers or collide with the CHANNEL:PARAM:VALUE separator
+        if name.is_empty() || name.contains(':') || name.parse::<i32>().is_ok() {
+            eprintln!("Error: Invalid group name '{}'. Use a non-numeric name without ':'", name);
+            std::process::exit(1);
+        }
+        
+        let mut channels = Vec::new();
+        for channel_str in channel_list.split(',') {
+            let channel = parse_channel(channel_str).unwrap_or_else(|e| {
+                eprintln!("Error: {} in group '{}'", e, name);
+                std::process::exit(1);
+            });
+            if !channels.contains(&channel) {
+                channels.push(channel);
+            }
+        }
+        
+        if channel_groups.insert(name.clone(), channels).is_some() {
+            eprintln!("Error: Group '{}' is defined more than once", name);
+            std::process::exit(1);
+        }
+    }
+    
     // Parse per-channel parameters
     for param_str in &args.channel_params {
         // Parse format: CHANNEL:PARAM:VALUE
@@ -329,15 +851,16 @@ fn main() {
             std::process::exit(1);
         }
         
-        let channel = match parts[0].parse::<i32>() {
-            Ok(ch) if ch >= 0 && ch < 16 => ch,
-            Ok(ch) => {
-                eprintln!("Error: Channel number must be 0-15, got {}", ch);
-                std::process::exit(1);
-            }
-            Err(e) => {
-                eprintln!("Error: Invalid channel number '{}': {}", parts[0], e);
-                std::process::exit(1);
+        // CHANNEL is either a group name (never numeric) or a channel number
+        let channels = match channel_groups.get(&parts[0].trim().to_lowercase()) {
+            Some(group_channels) => group_channels.clone(),
+            None => {
+                let channel = parse_channel(parts[0]).unwrap_or_else(|e| {
+                    eprintln!("Error: {}", e);
+                    eprintln!("CHANNEL must be 0-15 or a group defined with --group");
+                    std::process::exit(1);
+                });
+                vec![channel]
             }
         };
         
@@ -366,7 +889,9 @@ fn main() {
                     }
                 }
             };
-            cc_state_manager.set_channel_cc(channel, &param_type, value);
+            for &channel in &channels {
+                cc_state_manager.set_channel_cc(channel, &param_type, value);
+            }
         } else {
             // Other parameters: 0-127
             let value = match value_str.parse::<u8>() {
@@ -380,7 +905,9 @@ fn main() {
                     std::process::exit(1);
                 }
             };
-            cc_state_manager.set_channel_cc(channel, &param_type, value);
+            for &channel in &channels {
+                cc_state_manager.set_channel_cc(channel, &param_type, value);
+            }
         }
     }
     
@@ -404,8 +931,42 @@ fn main() {
     let left_clone = Arc::clone(&left);
     let right_clone = Arc::clone(&right);
     let cc_state_clone = Arc::clone(&cc_state);
+    let playhead: Arc<Mutex<Option<Playhead>>> = Arc::new(Mutex::new(None));
+    let playhead_clone = Arc::clone(&playhead);
+
+    // In sync mode the start time comes from (or is announced by) the network leader.
+    let scheduled_start = if args.sync_leader {
+        let start_at = scheduled_start
+            .unwrap_or_else(|| SystemTime::now() + Duration::from_millis(SYNC_DEFAULT_LEAD_MS));
+        let port = args.sync_port;
+        let playhead = Arc::clone(&playhead);
+        std::thread::spawn(move || run_sync_leader(port, start_at, playhead));
+        Some(start_at)
+    } else if let Some(socket) = sync_socket {
+        println!("Waiting for sync leader on port {}...", args.sync_port);
+        let (start_at, clock_offset_ms, leader) = wait_for_sync_start(&socket);
+        let sequencer = Arc::clone(&sequencer);
+        let playhead = Arc::clone(&playhead);
+        std::thread::spawn(move || run_sync_follower(socket, leader, clock_offset_ms, sequencer, playhead));
+        Some(start_at)
+    } else {
+        scheduled_start
+    };
+
+    // Everything is loaded. With a scheduled start the audio callback outputs silence
+    // until the main thread publishes the exact start instant.
+    if let Some(target) = scheduled_start {
+        if SystemTime::now() > target {
+            eprintln!("Warning: Loading took longer than the scheduled start; starting immediately");
+        }
+    }
+    let scheduled = scheduled_start.is_some();
+    let render_start: Arc<Mutex<Option<Instant>>> = Arc::new(Mutex::new(None));
+    let render_start_clone = Arc::clone(&render_start);
+    let sample_rate = params.sample_rate;
 
     // Start the audio output.
+    // The device is opened before the scheduled time, so its startup delay doesn't push the start back.
     let _device = run_output_device(params, {
         move |data| {
             // Lock and render audio.
@@ -414,16 +975,41 @@ fn main() {
             let mut left_buf = left_clone.lock().unwrap();
             let mut right_buf = right_clone.lock().unwrap();
             
-            // Render audio samples (this processes MIDI file events, including CC messages)
-            seq.render(&mut left_buf[..], &mut right_buf[..]);
+            // Output silence until the scheduled start, beginning mid-buffer if it falls inside this one
+            let silent_samples = if scheduled {
+                match *render_start_clone.lock().unwrap() {
+                    Some(start) => {
+                        let until_start = start.saturating_duration_since(Instant::now());
+                        ((until_start.as_secs_f64() * sample_rate as f64).round() as usize).min(left_buf.len())
+                    }
+                    // Not armed yet
+                    None => left_buf.len(),
+                }
+            } else {
+                0
+            };
+            left_buf[..silent_samples].fill(0.0);
+            right_buf[..silent_samples].fill(0.0);
             
-            // Send our CC messages AFTER render() to override any MIDI file CC messages
-            // This ensures our parameters take precedence
-            let cc_state_guard = cc_state_clone.lock().unwrap();
-            unsafe {
-                send_cc_messages_from_state(&*cc_state_guard, &*seq);
+            if silent_samples < left_buf.len() {
+                // Render audio samples (this processes MIDI file events, including CC messages)
+                seq.render(&mut left_buf[silent_samples..], &mut right_buf[silent_samples..]);
+                
+                // Record where playback is, for network sync position reports and corrections
+                *playhead_clone.lock().unwrap() = Some(Playhead {
+                    position: seq.get_position(),
+                    speed: seq.get_speed(),
+                    rendered_at: Instant::now(),
+                });
+                
+                // Send our CC messages AFTER render() to override any MIDI file CC messages
+                // This ensures our parameters take precedence
+                let cc_state_guard = cc_state_clone.lock().unwrap();
+                unsafe {
+                    send_cc_messages_from_state(&*cc_state_guard, &*seq);
+                }
+                drop(cc_state_guard);
             }
-            drop(cc_state_guard);
             
             // Interleave left and right channels.
             for (i, value) in left_buf.iter().interleave(right_buf.iter()).enumerate() {
@@ -433,7 +1019,219 @@ fn main() {
     })
     .unwrap();
 
+    if let Some(target) = scheduled_start {
+        wait_until_near(target);
+        
+        // Pin the start to the monotonic clock from the wall clock as it is right before the start.
+        // The audio callback and the playback timer below both follow this same instant.
+        let start = Instant::now() + target.duration_since(SystemTime::now()).unwrap_or_default();
+        *render_start.lock().unwrap() = Some(start);
+        std::thread::sleep(start.saturating_duration_since(Instant::now()));
+    }
+
     // Wait for the MIDI file to finish playing.
     // Calculate duration: MIDI file length in seconds
     std::thread::sleep(std::time::Duration::from_secs_f64(midi_duration_seconds));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::FixedOffset;
+
+    #[test]
+    fn channel_accepts_0_to_15_only() {
+        assert_eq!(parse_channel("0"), Ok(0));
+        assert_eq!(parse_channel(" 15 "), Ok(15));
+        assert!(parse_channel("16").is_err());
+        assert!(parse_channel("-1").is_err());
+        assert!(parse_channel("strings").is_err());
+        assert!(parse_channel("").is_err());
+    }
+
+    #[test]
+    fn start_at_accepts_local_and_offset_formats() {
+        let utc = parse_start_at("2024-12-31T23:59:30Z").unwrap();
+        assert_eq!(unix_millis(utc), 1_735_689_570_000);
+        
+        let offset = parse_start_at("2024-12-31T23:59:30+01:00").unwrap();
+        assert_eq!(unix_millis(utc) - unix_millis(offset), 3_600_000);
+        
+        let local_t = parse_start_at("2024-12-31T23:59:30").unwrap();
+        let local_space = parse_start_at("2024-12-31 23:59:30").unwrap();
+        assert_eq!(local_t, local_space);
+    }
+
+    #[test]
+    fn start_at_rejects_invalid_input() {
+        assert!(parse_start_at("").is_err());
+        assert!(parse_start_at("tomorrow").is_err());
+        assert!(parse_start_at("2024-12-31").is_err());
+        assert!(parse_start_at("2024-13-01T00:00:00").is_err());
+        assert!(parse_start_at("2024-12-31T25:00:00").is_err());
+    }
+
+    #[test]
+    fn start_at_resolves_dst_transitions() {
+        let zone = FixedOffset::east_opt(0).unwrap();
+        let first = zone.with_ymd_and_hms(2024, 10, 27, 1, 30, 0).unwrap();
+        let second = zone.with_ymd_and_hms(2024, 10, 27, 2, 30, 0).unwrap();
+        
+        let ambiguous = resolve_local_start("x", LocalResult::Ambiguous(first, second)).unwrap();
+        assert_eq!(ambiguous, SystemTime::from(first));
+        
+        let single = resolve_local_start("x", LocalResult::Single(second)).unwrap();
+        assert_eq!(single, SystemTime::from(second));
+        
+        assert!(resolve_local_start::<FixedOffset>("x", LocalResult::None).is_err());
+    }
+
+    #[test]
+    fn start_in_accepts_units_and_compound_values() {
+        assert_eq!(parse_start_in("90").unwrap(), Duration::from_secs(90));
+        assert_eq!(parse_start_in("1.5").unwrap(), Duration::from_millis(1500));
+        assert_eq!(parse_start_in("90s").unwrap(), Duration::from_secs(90));
+        assert_eq!(parse_start_in("500ms").unwrap(), Duration::from_millis(500));
+        assert_eq!(parse_start_in("5m").unwrap(), Duration::from_secs(300));
+        assert_eq!(parse_start_in("2h").unwrap(), Duration::from_secs(7200));
+        assert_eq!(parse_start_in("1h30m").unwrap(), Duration::from_secs(5400));
+        assert_eq!(parse_start_in("1m30s").unwrap(), Duration::from_secs(90));
+        assert_eq!(parse_start_in(" 10S ").unwrap(), Duration::from_secs(10));
+        assert_eq!(parse_start_in("0s").unwrap(), Duration::ZERO);
+    }
+
+    #[test]
+    fn start_in_rejects_invalid_input() {
+        assert!(parse_start_in("").is_err());
+        assert!(parse_start_in("   ").is_err());
+        assert!(parse_start_in("-5").is_err());
+        assert!(parse_start_in("-5s").is_err());
+        assert!(parse_start_in("inf").is_err());
+        assert!(parse_start_in("nan").is_err());
+        assert!(parse_start_in("s").is_err());
+        assert!(parse_start_in("10x").is_err());
+        assert!(parse_start_in("10d").is_err());
+        assert!(parse_start_in("1..5s").is_err());
+    }
+
+    #[test]
+    fn countdown_format() {
+        assert_eq!(format_countdown(0), "0:00");
+        assert_eq!(format_countdown(59), "0:59");
+        assert_eq!(format_countdown(90), "1:30");
+        assert_eq!(format_countdown(3600), "1:00:00");
+        assert_eq!(format_countdown(86_399), "23:59:59");
+        assert_eq!(format_countdown(90_061), "25:01:01");
+    }
+
+    #[test]
+    fn start_in_rejects_overflow() {
+        assert!(parse_start_in("1e30").is_err());
+        assert!(parse_start_in("99999999999999999999h").is_err());
+        assert!(parse_start_in("99999999999999999999").is_err());
+    }
+
+    #[test]
+    fn sync_messages_round_trip() {
+        let start = SyncMessage::Start { sent_at_ms: 1_735_689_560_123, start_at_ms: 1_735_689_570_000 };
+        match SyncMessage::decode(start.encode().as_bytes()) {
+            Some(SyncMessage::Start { sent_at_ms, start_at_ms }) => {
+                assert_eq!(sent_at_ms, 1_735_689_560_123);
+                assert_eq!(start_at_ms, 1_735_689_570_000);
+            }
+            other => panic!("unexpected decode result: {:?}", other),
+        }
+        
+        let position = SyncMessage::Position { sent_at_ms: -42, position: 12.345678 };
+        match SyncMessage::decode(position.encode().as_bytes()) {
+            Some(SyncMessage::Position { sent_at_ms, position }) => {
+                assert_eq!(sent_at_ms, -42);
+                assert_eq!(position, 12.345678);
+            }
+            other => panic!("unexpected decode result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn sync_messages_reject_malformed_datagrams() {
+        assert!(SyncMessage::decode(b"").is_none());
+        assert!(SyncMessage::decode(b"OTHER START 1 2").is_none());
+        assert!(SyncMessage::decode(b"RSPSYNC1 START 1").is_none());
+        assert!(SyncMessage::decode(b"RSPSYNC1 START 1 2 3").is_none());
+        assert!(SyncMessage::decode(b"RSPSYNC1 STOP 1 2").is_none());
+        assert!(SyncMessage::decode(b"RSPSYNC1 START 1 2.5").is_none());
+        assert!(SyncMessage::decode(b"RSPSYNC1 POS x 2.5").is_none());
+        assert!(SyncMessage::decode(b"RSPSYNC1 POS 1 NaN").is_none());
+        assert!(SyncMessage::decode(b"RSPSYNC1 POS 1 inf").is_none());
+        assert!(SyncMessage::decode(b"RSPSYNC1 POS 1 -inf").is_none());
+        assert!(SyncMessage::decode(&[0xff, 0xfe, 0x00]).is_none());
+    }
+
+    #[test]
+    fn sync_messages_reject_out_of_range_timestamps() {
+        assert!(SyncMessage::decode(b"RSPSYNC1 START -9223372036854775808 0").is_none());
+        assert!(SyncMessage::decode(b"RSPSYNC1 START 0 9223372036854775807").is_none());
+        assert!(SyncMessage::decode(b"RSPSYNC1 START 0 253402300800000").is_none());
+        assert!(SyncMessage::decode(b"RSPSYNC1 POS -9223372036854775808 1.0").is_none());
+        assert!(SyncMessage::decode(b"RSPSYNC1 START 0 253402300799999").is_some());
+        
+        // Extreme but accepted values must not overflow the follower's clock arithmetic
+        let mut tracker = SyncStartTracker::default();
+        let leader: SocketAddr = "192.168.1.10:47800".parse().unwrap();
+        tracker.receive(b"RSPSYNC1 START -253402300799999 253402300799999", leader, SYNC_MAX_TIMESTAMP_MS, false).unwrap();
+        assert_eq!(tracker.clock_offset_ms, Some(2 * SYNC_MAX_TIMESTAMP_MS));
+        assert!(tracker.local_start_ms().is_some());
+    }
+
+    #[test]
+    fn sync_follower_detects_leader_already_playing_in_backlog() {
+        let leader: SocketAddr = "192.168.1.10:47800".parse().unwrap();
+        let mut tracker = SyncStartTracker::default();
+        
+        // Queued while loading: the leader announced its start, then began reporting positions
+        tracker.receive(b"RSPSYNC1 START 9000 10000", leader, 20_000, true).unwrap();
+        tracker.receive(b"RSPSYNC1 START 9750 10000", leader, 20_000, true).unwrap();
+        assert_eq!(tracker.clock_offset_ms, None);
+        assert_eq!(tracker.local_start_ms(), None);
+        assert!(tracker.receive(b"RSPSYNC1 POS 11000 1.0", leader, 20_000, true).is_err());
+    }
+
+    #[test]
+    fn sync_follower_takes_clock_offset_from_fresh_datagrams_only() {
+        let leader: SocketAddr = "192.168.1.10:47800".parse().unwrap();
+        let mut tracker = SyncStartTracker::default();
+        
+        tracker.receive(b"RSPSYNC1 START 1000 10000", leader, 5_000, true).unwrap();
+        tracker.receive(b"RSPSYNC1 START 5000 10000", leader, 5_505, false).unwrap();
+        tracker.receive(b"RSPSYNC1 START 5250 10000", leader, 5_752, false).unwrap();
+        assert_eq!(tracker.clock_offset_ms, Some(502));
+        assert_eq!(tracker.local_start_ms(), Some(10_502));
+        
+        // Position reports after the start are not an error once the start is known
+        assert!(tracker.receive(b"RSPSYNC1 POS 11000 1.0", leader, 11_600, false).is_ok());
+    }
+
+    #[test]
+    fn sync_follower_locks_onto_first_leader() {
+        let leader: SocketAddr = "192.168.1.10:47800".parse().unwrap();
+        let other: SocketAddr = "192.168.1.20:47800".parse().unwrap();
+        let mut tracker = SyncStartTracker::default();
+        
+        tracker.receive(b"RSPSYNC1 START 5000 10000", leader, 5_100, false).unwrap();
+        tracker.receive(b"RSPSYNC1 START 5000 90000", other, 4_000, false).unwrap();
+        assert!(tracker.receive(b"RSPSYNC1 POS 5000 3.0", other, 5_100, false).is_ok());
+        assert_eq!(tracker.leader, Some(leader));
+        assert_eq!(tracker.clock_offset_ms, Some(100));
+        assert_eq!(tracker.local_start_ms(), Some(10_100));
+        
+        // Garbage from anyone is ignored
+        assert!(tracker.receive(b"hello", other, 5_200, false).is_ok());
+    }
+
+    #[test]
+    fn unix_millis_round_trip() {
+        for ms in [0, 1, 1_735_689_570_000, -1_000] {
+            assert_eq!(unix_millis(from_unix_millis(ms).unwrap()), ms);
+        }
+    }
+}